@@ -0,0 +1,36 @@
+use std::fmt;
+
+/// Errors produced while parsing an instruction listing, carrying the
+/// 1-based line number so a caller can report exactly what went wrong.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidLine(usize),
+    UnknownAction(usize, char),
+    BadValue(usize),
+    TrailingInput(usize, usize),
+    NonRightAngle(usize, i64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLine(line) => write!(f, "line {}: could not parse instruction", line),
+            Error::UnknownAction(line, action) => {
+                write!(f, "line {}: unknown action '{}'", line, action)
+            }
+            Error::BadValue(line) => write!(f, "line {}: invalid action value", line),
+            Error::TrailingInput(line, offset) => write!(
+                f,
+                "line {}: unexpected trailing input at byte {}",
+                line, offset
+            ),
+            Error::NonRightAngle(line, degrees) => write!(
+                f,
+                "line {}: rotation {} is not a multiple of 90",
+                line, degrees
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
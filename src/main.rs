@@ -1,8 +1,13 @@
-use lazy_static::lazy_static;
-use regex::Regex;
+mod error;
+mod parser;
+mod svg;
+mod vec2;
 
-#[derive(Debug)]
-enum Instruction {
+use error::Error;
+use vec2::Vec2;
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Instruction {
     North(i64),
     South(i64),
     East(i64),
@@ -12,133 +17,107 @@ enum Instruction {
     Left(i64),
 }
 
-lazy_static! {
-    static ref INSTRUCTION_REGEX: Regex =
-        Regex::new(r"^(?P<action>\w)(?P<value>\d+)").expect("invalid regex");
-}
-
-fn read_instructions<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<Instruction> {
-    let mut instructions = Vec::new();
-    for line in lines {
-        let caps = INSTRUCTION_REGEX
-            .captures(line)
-            .expect("invalid instruction line");
-        let action = &caps["action"];
-        let value = caps["value"].parse::<i64>().expect("invalid action value");
-        let instruction = match action {
-            "N" => Instruction::North(value),
-            "S" => Instruction::South(value),
-            "E" => Instruction::East(value),
-            "W" => Instruction::West(value),
-            "F" => Instruction::Forward(value),
-            "R" => {
-                if value % 90 != 0 {
-                    panic!("invalid rotation {}", value);
-                }
-                Instruction::Right(value)
-            }
-            "L" => {
-                if value % 90 != 0 {
-                    panic!("invalid rotation {}", value);
-                }
-                Instruction::Left(value)
-            }
-            other => panic!("invalid instruction: {}{}", other, value),
-        };
-        instructions.push(instruction);
-    }
-    instructions
-}
-
-fn rotate_waypoint(x: i64, y: i64, rotation: i64) -> (i64, i64) {
-    let r = ((x.pow(2) + y.pow(2)) as f64).sqrt();
-    let mut theta = (y as f64).atan2(x as f64);
-    theta -= (rotation as f64).to_radians();
-    let new_x = (r * theta.cos()).round() as i64;
-    let new_y = (r * theta.sin()).round() as i64;
-    (new_x, new_y)
+fn read_instructions<'a>(
+    lines: impl Iterator<Item = &'a str>,
+) -> Result<Vec<Instruction>, Error> {
+    lines
+        .enumerate()
+        .map(|(index, line)| parser::parse_instruction(line, index + 1))
+        .collect()
 }
 
 struct Ship {
-    north: i64,
-    east: i64,
-    waypoint_north: i64,
-    waypoint_east: i64,
-    facing: (i64, i64),
+    position: Vec2,
+    waypoint: Vec2,
+    facing: Vec2,
+    trajectory: Option<Vec<Vec2>>,
 }
 
 impl Ship {
     fn new() -> Ship {
         Ship {
-            north: 0,
-            east: 0,
-            waypoint_north: 1,
-            waypoint_east: 10,
-            facing: (1, 0),
+            position: Vec2::new(0, 0),
+            waypoint: Vec2::new(10, 1),
+            facing: Vec2::new(1, 0),
+            trajectory: None,
         }
     }
 
-    fn plot(&mut self, instructions: &[Instruction]) -> (i64, i64) {
+    /// Enables recording of every intermediate position visited by `plot`
+    /// or `plot_with_waypoint`, retrievable afterwards via [`Ship::trajectory`].
+    fn with_trajectory(mut self) -> Ship {
+        self.trajectory = Some(vec![self.position]);
+        self
+    }
+
+    fn trajectory(&self) -> Option<&[Vec2]> {
+        self.trajectory.as_deref()
+    }
+
+    fn record_position(&mut self) {
+        if let Some(trajectory) = &mut self.trajectory {
+            trajectory.push(self.position);
+        }
+    }
+
+    fn plot(&mut self, instructions: &[Instruction]) -> Vec2 {
         for instruction in instructions {
             match instruction {
-                Instruction::North(value) => self.north += *value,
-                Instruction::South(value) => self.north -= *value,
-                Instruction::East(value) => self.east += *value,
-                Instruction::West(value) => self.east -= *value,
-                Instruction::Forward(value) => {
-                    let (east, north) = self.facing;
-                    self.north += north * value;
-                    self.east += east * value;
-                }
+                Instruction::North(value) => self.position = self.position + Vec2::new(0, *value),
+                Instruction::South(value) => self.position = self.position - Vec2::new(0, *value),
+                Instruction::East(value) => self.position = self.position + Vec2::new(*value, 0),
+                Instruction::West(value) => self.position = self.position - Vec2::new(*value, 0),
+                Instruction::Forward(value) => self.position = self.position + self.facing * *value,
                 Instruction::Right(degrees) => {
-                    let (x, y) = self.facing;
-                    self.facing = rotate_waypoint(x, y, *degrees);
+                    self.facing = self.facing.rotate_quarters(degrees / 90)
                 }
                 Instruction::Left(degrees) => {
-                    let (x, y) = self.facing;
-                    self.facing = rotate_waypoint(x, y, *degrees * -1);
+                    self.facing = self.facing.rotate_quarters(-degrees / 90)
                 }
             }
+            self.record_position();
         }
-        (self.north, self.east)
+        self.position
     }
 
-    fn plot_with_waypoint(&mut self, instructions: &[Instruction]) -> (i64, i64) {
+    fn plot_with_waypoint(&mut self, instructions: &[Instruction]) -> Vec2 {
         for instruction in instructions {
             match instruction {
-                Instruction::North(value) => self.waypoint_north += value,
-                Instruction::South(value) => self.waypoint_north -= value,
-                Instruction::East(value) => self.waypoint_east += value,
-                Instruction::West(value) => self.waypoint_east -= value,
+                Instruction::North(value) => self.waypoint = self.waypoint + Vec2::new(0, *value),
+                Instruction::South(value) => self.waypoint = self.waypoint - Vec2::new(0, *value),
+                Instruction::East(value) => self.waypoint = self.waypoint + Vec2::new(*value, 0),
+                Instruction::West(value) => self.waypoint = self.waypoint - Vec2::new(*value, 0),
                 Instruction::Forward(value) => {
-                    self.north += value * self.waypoint_north;
-                    self.east += value * self.waypoint_east;
+                    self.position = self.position + self.waypoint * *value
                 }
                 Instruction::Right(degrees) => {
-                    let (new_east, new_north) =
-                        rotate_waypoint(self.waypoint_east, self.waypoint_north, *degrees);
-                    self.waypoint_east = new_east;
-                    self.waypoint_north = new_north;
+                    self.waypoint = self.waypoint.rotate_quarters(degrees / 90)
                 }
                 Instruction::Left(degrees) => {
-                    let (new_east, new_north) =
-                        rotate_waypoint(self.waypoint_east, self.waypoint_north, *degrees * -1);
-                    self.waypoint_east = new_east;
-                    self.waypoint_north = new_north;
+                    self.waypoint = self.waypoint.rotate_quarters(-degrees / 90)
                 }
             }
+            self.record_position();
         }
-        (self.north, self.east)
+        self.position
     }
 }
 
 fn main() {
-    let instructions = read_instructions(include_str!("../input.txt").lines());
-    let (north, east) = Ship::new().plot(&instructions);
-    println!("manhattan distance is {}", north.abs() + east.abs());
-
-    let (north, east) = Ship::new().plot_with_waypoint(&instructions);
-    println!("manhattan distance is {}", north.abs() + east.abs());
+    let instructions = read_instructions(include_str!("../input.txt").lines())
+        .expect("failed to parse instructions");
+
+    let mut ship = Ship::new().with_trajectory();
+    let distance = ship.plot(&instructions).manhattan();
+    println!("manhattan distance is {}", distance);
+    std::fs::write(
+        "trajectory.svg",
+        svg::trajectory_to_svg(ship.trajectory().unwrap()),
+    )
+    .expect("failed to write trajectory.svg");
+
+    let distance = Ship::new().plot_with_waypoint(&instructions).manhattan();
+    println!("manhattan distance is {}", distance);
 }
 
 #[cfg(test)]
@@ -154,31 +133,58 @@ mod tests {
     F11"};
 
     #[test]
-    fn rotation_is_correctly_applied() {
-        assert_eq!(rotate_waypoint(1, 0, 90), (0, -1));
-        assert_eq!(rotate_waypoint(0, -1, 90), (-1, 0));
-        assert_eq!(rotate_waypoint(-1, 0, 90), (0, 1));
-        assert_eq!(rotate_waypoint(0, 1, 90), (1, 0));
-
-        assert_eq!(rotate_waypoint(1, 0, -90), (0, 1));
-        assert_eq!(rotate_waypoint(0, -1, -90), (1, 0));
-        assert_eq!(rotate_waypoint(-1, 0, -90), (0, -1));
-        assert_eq!(rotate_waypoint(0, 1, -90), (-1, 0));
+    fn plot_is_correctly_calculated() {
+        let instructions = read_instructions(TEST_JOURNEY.lines()).unwrap();
+        let mut ship = Ship::new();
+        assert_eq!(25, ship.plot(&instructions).manhattan());
     }
 
     #[test]
-    fn plot_is_correctly_calculated() {
-        let instructions = read_instructions(TEST_JOURNEY.lines());
+    fn waypoint_plot_is_correctly_calculated() {
+        let instructions = read_instructions(TEST_JOURNEY.lines()).unwrap();
         let mut ship = Ship::new();
-        let (north, east) = ship.plot(&instructions);
-        assert_eq!(25, north.abs() + east.abs());
+        assert_eq!(286, ship.plot_with_waypoint(&instructions).manhattan());
     }
 
     #[test]
-    fn waypoint_plot_is_correctly_calculated() {
-        let instructions = read_instructions(TEST_JOURNEY.lines());
+    fn unknown_action_is_reported_with_line_number() {
+        let err = read_instructions(["F10", "X3"].into_iter()).unwrap_err();
+        assert_eq!(err, Error::UnknownAction(2, 'X'));
+    }
+
+    #[test]
+    fn non_right_angle_rotation_is_rejected() {
+        let err = read_instructions(["R45"].into_iter()).unwrap_err();
+        assert_eq!(err, Error::NonRightAngle(1, 45));
+    }
+
+    #[test]
+    fn invalid_line_is_reported_with_line_number() {
+        let err = read_instructions(["F10", ""].into_iter()).unwrap_err();
+        assert_eq!(err, Error::InvalidLine(2));
+    }
+
+    #[test]
+    fn trailing_input_is_reported_with_line_number() {
+        let err = read_instructions(["F10", "F10x"].into_iter()).unwrap_err();
+        assert_eq!(err, Error::TrailingInput(2, 3));
+    }
+
+    #[test]
+    fn trajectory_is_not_recorded_by_default() {
+        let instructions = read_instructions(TEST_JOURNEY.lines()).unwrap();
         let mut ship = Ship::new();
-        let (north, east) = ship.plot_with_waypoint(&instructions);
-        assert_eq!(286, north.abs() + east.abs());
+        ship.plot(&instructions);
+        assert!(ship.trajectory().is_none());
+    }
+
+    #[test]
+    fn trajectory_records_every_intermediate_position() {
+        let instructions = read_instructions(TEST_JOURNEY.lines()).unwrap();
+        let mut ship = Ship::new().with_trajectory();
+        ship.plot(&instructions);
+        let trajectory = ship.trajectory().unwrap();
+        assert_eq!(trajectory.len(), instructions.len() + 1);
+        assert_eq!(*trajectory.last().unwrap(), Vec2::new(17, -8));
     }
 }
@@ -0,0 +1,57 @@
+use crate::error::Error;
+use crate::Instruction;
+
+/// Parses a single instruction line of the form `<action><value>`, e.g.
+/// `F10` or `R90`. Rejects anything left over after the digits instead of
+/// silently ignoring it.
+pub fn parse_instruction(line: &str, line_number: usize) -> Result<Instruction, Error> {
+    let action = line.chars().next().ok_or(Error::InvalidLine(line_number))?;
+    let rest = &line[action.len_utf8()..];
+
+    let digit_len = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    if digit_len == 0 {
+        return Err(Error::BadValue(line_number));
+    }
+    if digit_len != rest.len() {
+        return Err(Error::TrailingInput(
+            line_number,
+            action.len_utf8() + digit_len,
+        ));
+    }
+    let value = rest.parse::<i64>().map_err(|_| Error::BadValue(line_number))?;
+
+    let instruction = match action {
+        'N' => Instruction::North(value),
+        'S' => Instruction::South(value),
+        'E' => Instruction::East(value),
+        'W' => Instruction::West(value),
+        'F' => Instruction::Forward(value),
+        'R' => {
+            if value % 90 != 0 {
+                return Err(Error::NonRightAngle(line_number, value));
+            }
+            Instruction::Right(value)
+        }
+        'L' => {
+            if value % 90 != 0 {
+                return Err(Error::NonRightAngle(line_number, value));
+            }
+            Instruction::Left(value)
+        }
+        other => return Err(Error::UnknownAction(line_number, other)),
+    };
+    Ok(instruction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trailing_input_is_rejected_with_byte_offset() {
+        assert_eq!(
+            parse_instruction("F10x", 1),
+            Err(Error::TrailingInput(1, 3))
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use crate::vec2::Vec2;
+
+/// Serializes a recorded trajectory as an SVG document containing a single
+/// `<polyline>`, with the `viewBox` sized to fit every point. North is
+/// rendered upward, so the `y` axis is flipped relative to SVG's
+/// downward-growing coordinate space.
+pub fn trajectory_to_svg(trajectory: &[Vec2]) -> String {
+    if trajectory.is_empty() {
+        return "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 0 0\"></svg>".to_string();
+    }
+
+    let (min_x, max_x, min_y, max_y) = trajectory.iter().fold(
+        (i64::MAX, i64::MIN, i64::MAX, i64::MIN),
+        |(min_x, max_x, min_y, max_y), point| {
+            (
+                min_x.min(point.x),
+                max_x.max(point.x),
+                min_y.min(point.y),
+                max_y.max(point.y),
+            )
+        },
+    );
+
+    let points: String = trajectory
+        .iter()
+        .map(|point| format!("{},{}", point.x, -point.y))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n  \
+         <polyline points=\"{}\" fill=\"none\" stroke=\"black\" />\n\
+         </svg>",
+        min_x,
+        -max_y,
+        max_x - min_x,
+        max_y - min_y,
+        points
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_box_fits_every_point() {
+        let trajectory = [Vec2::new(0, 0), Vec2::new(10, 4), Vec2::new(-3, 1)];
+        let svg = trajectory_to_svg(&trajectory);
+        assert!(svg.contains("viewBox=\"-3 -4 13 4\""));
+        assert!(svg.contains("<polyline points=\"0,0 10,-4 -3,-1\""));
+    }
+}
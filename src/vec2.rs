@@ -0,0 +1,76 @@
+use std::ops::{Add, Mul, Sub};
+
+/// A 2D integer vector used for ship/waypoint positions, with `x` as the
+/// east/west component and `y` as the north/south component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub fn new(x: i64, y: i64) -> Vec2 {
+        Vec2 { x, y }
+    }
+
+    pub fn manhattan(&self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Rotates clockwise by `quarters` quarter-turns (negative for
+    /// counter-clockwise).
+    pub fn rotate_quarters(&self, quarters: i64) -> Vec2 {
+        let mut point = *self;
+        for _ in 0..quarters.rem_euclid(4) {
+            point = Vec2::new(point.y, -point.x);
+        }
+        point
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, scalar: i64) -> Vec2 {
+        Vec2::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation_is_correctly_applied() {
+        assert_eq!(Vec2::new(1, 0).rotate_quarters(1), Vec2::new(0, -1));
+        assert_eq!(Vec2::new(0, -1).rotate_quarters(1), Vec2::new(-1, 0));
+        assert_eq!(Vec2::new(-1, 0).rotate_quarters(1), Vec2::new(0, 1));
+        assert_eq!(Vec2::new(0, 1).rotate_quarters(1), Vec2::new(1, 0));
+
+        assert_eq!(Vec2::new(1, 0).rotate_quarters(-1), Vec2::new(0, 1));
+        assert_eq!(Vec2::new(0, -1).rotate_quarters(-1), Vec2::new(1, 0));
+        assert_eq!(Vec2::new(-1, 0).rotate_quarters(-1), Vec2::new(0, -1));
+        assert_eq!(Vec2::new(0, 1).rotate_quarters(-1), Vec2::new(-1, 0));
+    }
+
+    #[test]
+    fn manhattan_sums_absolute_components() {
+        assert_eq!(Vec2::new(-3, 4).manhattan(), 7);
+    }
+}